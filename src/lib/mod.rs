@@ -1,31 +1,77 @@
 use core::fmt;
 use core::time::Duration;
+use serde::{Deserialize, Serialize};
 use serialport::SerialPortSettings;
-use std::io;
+use std::io::{self, Read, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
 pub use serialport;
 
+/// Frames on the wire are COBS-encoded and separated by a single `0x00`, so a
+/// reader that loses sync can always resync on the next delimiter.
+const FRAME_DELIMITER: u8 = 0x00;
+
+/// A command sent from the host to the micro-controller.
+#[derive(Debug, Serialize, Deserialize)]
+enum Command {
+    Keepalive,
+    Speed(i16),
+}
+
+/// A status frame sent back by the micro-controller, reporting the current measured
+/// state of the ventilator rather than a per-command acknowledgement.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Status {
+    pub pressure: f32,
+    pub flow: f32,
+    pub rpm: u16,
+    pub error_flags: u8,
+}
+
+/// Serial line settings used to open the connection to the micro-controller.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub baud_rate: u32,
+    pub timeout: Duration,
+    pub parity: serialport::Parity,
+    pub stop_bits: serialport::StopBits,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            baud_rate: 115200,
+            timeout: Duration::from_millis(1000),
+            parity: serialport::Parity::None,
+            stop_bits: serialport::StopBits::One,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     WrongDevice,
     Disconnected,
     UnsupportedVersion(u16),
-    InvalidSpeed,
+    Busy,
     Open(serialport::Error),
     Other(io::Error),
 }
 
 #[allow(non_camel_case_types)]
 mod ffi {
-    use crate::Error;
+    use crate::{ConnectionEvent, Error, RetryPolicy, Status};
     use core::ptr;
     use std::ffi::{CStr, CString};
     use std::os::raw::{c_char, c_int};
-    use std::sync::mpsc::Sender;
+    use std::sync::mpsc::{Receiver, Sender};
+    use std::sync::{Arc, Mutex};
     use std::thread::JoinHandle;
+    use std::time::Duration;
 
     #[repr(C)]
     pub struct error_t {
@@ -39,7 +85,7 @@ mod ffi {
         WRONG_DEVICE,
         DISCONNECTED,
         UNSUPPORTED_VERSION,
-        INVALID_SPEED,
+        BUSY,
         OPEN,
         COMMUNICATION,
     }
@@ -53,9 +99,67 @@ mod ffi {
         }
     }
 
-    pub struct handle_t(JoinHandle<Result<(), Error>>);
+    pub struct handle_t {
+        thread: JoinHandle<Result<(), Error>>,
+        status: Receiver<Status>,
+    }
     pub struct sender_t(Sender<i16>);
 
+    /// C-layout mirror of [`Status`], filled in by `smov_poll_status`.
+    #[repr(C)]
+    pub struct status_t {
+        pub pressure: f32,
+        pub flow: f32,
+        pub rpm: u16,
+        pub error_flags: u8,
+    }
+
+    impl From<Status> for status_t {
+        fn from(s: Status) -> Self {
+            Self {
+                pressure: s.pressure,
+                flow: s.flow,
+                rpm: s.rpm,
+                error_flags: s.error_flags,
+            }
+        }
+    }
+
+    pub struct supervised_handle_t {
+        status: Receiver<Status>,
+        events: Receiver<ConnectionEvent>,
+        policy: Arc<Mutex<RetryPolicy>>,
+    }
+
+    #[repr(C)]
+    pub enum connection_event_t {
+        RECONNECTING,
+        RECONNECTED,
+        GAVE_UP,
+    }
+
+    impl From<ConnectionEvent> for connection_event_t {
+        fn from(e: ConnectionEvent) -> Self {
+            match e {
+                ConnectionEvent::Reconnecting => Self::RECONNECTING,
+                ConnectionEvent::Reconnected => Self::RECONNECTED,
+                ConnectionEvent::GaveUp => Self::GAVE_UP,
+            }
+        }
+    }
+
+    fn retry_policy(base_delay_ms: u32, max_delay_ms: u32, max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(base_delay_ms as u64),
+            max_delay: Duration::from_millis(max_delay_ms as u64),
+            max_attempts: if max_attempts == 0 {
+                None
+            } else {
+                Some(max_attempts)
+            },
+        }
+    }
+
     fn convert_err(e: Error) -> error_t {
         fn err(t: error_type) -> error_t {
             error_t { tag: t, payload: 0 }
@@ -69,7 +173,7 @@ mod ffi {
             Error::WrongDevice => err(error_type::WRONG_DEVICE),
             Error::Disconnected => err(error_type::DISCONNECTED),
             Error::UnsupportedVersion(v) => err_p(error_type::UNSUPPORTED_VERSION, v as _),
-            Error::InvalidSpeed => err(error_type::INVALID_SPEED),
+            Error::Busy => err(error_type::BUSY),
             Error::Open(_) => err(error_type::OPEN), // TODO: Add payload
             Error::Other(e) => err_p(error_type::COMMUNICATION, e.raw_os_error().unwrap_or(0)),
         }
@@ -92,10 +196,38 @@ mod ffi {
         sender: *mut *mut sender_t,
         handle: *mut *mut handle_t,
     ) -> error_t {
-        match super::communicate(CStr::from_ptr(serial).to_str().unwrap()) {
-            Ok((s, h)) => {
+        smov_connect_with_config(
+            serial,
+            super::Config::default().baud_rate,
+            1000,
+            sender,
+            handle,
+        )
+    }
+
+    /// Start communication with the device, overriding the default serial settings
+    ///
+    /// Arguments:
+    ///   serial, sender, handle: see smov_connect
+    ///   baud_rate: the baud rate to open the serial port with
+    ///   timeout_ms: the read/write timeout, in milliseconds
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_connect_with_config(
+        serial: *const c_char,
+        baud_rate: u32,
+        timeout_ms: u32,
+        sender: *mut *mut sender_t,
+        handle: *mut *mut handle_t,
+    ) -> error_t {
+        let config = super::Config {
+            baud_rate,
+            timeout: std::time::Duration::from_millis(timeout_ms as u64),
+            ..super::Config::default()
+        };
+        match super::communicate(CStr::from_ptr(serial).to_str().unwrap(), config) {
+            Ok((s, h, status)) => {
                 *sender = Box::into_raw(Box::new(sender_t(s)));
-                *handle = Box::into_raw(Box::new(handle_t(h)));
+                *handle = Box::into_raw(Box::new(handle_t { thread: h, status }));
                 error_t::ok()
             }
             Err(e) => convert_err(e),
@@ -162,7 +294,7 @@ mod ffi {
     ///   The reason the communication stopped
     #[no_mangle]
     pub unsafe extern "C" fn smov_get_error(handle: *const handle_t) -> error_t {
-        match handle.read().0.join() {
+        match handle.read().thread.join() {
             Ok(Ok(())) => error_t::ok(),
             Ok(Err(e)) => convert_err(e),
             Err(_) => error_t {
@@ -172,6 +304,119 @@ mod ffi {
         }
     }
 
+    /// Drain one pending telemetry update without blocking
+    ///
+    /// Arguments:
+    ///   handle: the pointer to the handle reference handed out by smov_connect. Should
+    ///     point to a non-null, valid reference.
+    ///   status: the pointer to which the telemetry is written if one is available.
+    ///
+    /// Return:
+    ///   true: a status was available and has been written to `status`
+    ///   false: no telemetry was pending; `status` is left untouched
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_poll_status(
+        handle: *const handle_t,
+        status: *mut status_t,
+    ) -> bool {
+        match (&*handle).status.try_recv() {
+            Ok(s) => {
+                *status = s.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Start a supervised connection that automatically re-establishes the link (closing
+    /// the port, backing off, reopening and re-running the handshake) if the device
+    /// disconnects, instead of ending the session.
+    ///
+    /// Arguments:
+    ///   serial, baud_rate, timeout_ms: see smov_connect_with_config
+    ///   base_delay_ms, max_delay_ms: the exponential backoff schedule between attempts
+    ///   max_attempts: give up and report ConnectionEvent::GAVE_UP after this many failed
+    ///     attempts in a row. 0 means retry forever.
+    ///   sender: the pointer to the sender will be written to this field.
+    ///   handle: the pointer to the supervised handle, used to poll telemetry and link
+    ///     state and to adjust the retry policy.
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_connect_supervised(
+        serial: *const c_char,
+        baud_rate: u32,
+        timeout_ms: u32,
+        base_delay_ms: u32,
+        max_delay_ms: u32,
+        max_attempts: u32,
+        sender: *mut *mut sender_t,
+        handle: *mut *mut supervised_handle_t,
+    ) {
+        let config = super::Config {
+            baud_rate,
+            timeout: Duration::from_millis(timeout_ms as u64),
+            ..super::Config::default()
+        };
+        let policy = Arc::new(Mutex::new(retry_policy(
+            base_delay_ms,
+            max_delay_ms,
+            max_attempts,
+        )));
+        let path = CStr::from_ptr(serial).to_str().unwrap().to_owned();
+        let (s, status, events) = super::communicate_supervised(path, config, policy.clone());
+        *sender = Box::into_raw(Box::new(sender_t(s)));
+        *handle = Box::into_raw(Box::new(supervised_handle_t {
+            status,
+            events,
+            policy,
+        }));
+    }
+
+    /// Drain one pending telemetry update from a supervised connection without blocking
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_poll_status_supervised(
+        handle: *const supervised_handle_t,
+        status: *mut status_t,
+    ) -> bool {
+        match (&*handle).status.try_recv() {
+            Ok(s) => {
+                *status = s.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Drain one pending link-state transition without blocking
+    ///
+    /// Return:
+    ///   true: a transition was pending and has been written to `event`
+    ///   false: the link state hasn't changed since the last call
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_poll_connection_event(
+        handle: *const supervised_handle_t,
+        event: *mut connection_event_t,
+    ) -> bool {
+        match (&*handle).events.try_recv() {
+            Ok(e) => {
+                *event = e.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Change the backoff schedule used by an already-running supervised connection
+    #[no_mangle]
+    pub unsafe extern "C" fn smov_set_reconnect_policy(
+        handle: *const supervised_handle_t,
+        base_delay_ms: u32,
+        max_delay_ms: u32,
+        max_attempts: u32,
+    ) {
+        *(&*handle).policy.lock().unwrap() =
+            retry_policy(base_delay_ms, max_delay_ms, max_attempts);
+    }
+
     /// Convert an error code to a string representation
     #[no_mangle]
     pub unsafe extern "C" fn smov_strerror(error: error_t) -> *const c_char {
@@ -183,7 +428,7 @@ mod ffi {
             }
             DISCONNECTED => "The device disconnected unexpectedly\0",
             UNSUPPORTED_VERSION => "The device has an unsupported version\0",
-            INVALID_SPEED => "Could not set the speed on the device\0",
+            BUSY => "The device is already in use by another process\0",
             OPEN => "Could not open the device\0",
             COMMUNICATION => "Communication failure\0",
         }
@@ -223,60 +468,629 @@ impl fmt::Display for Error {
             Self::WrongDevice => write!(f, "the device connected is not responding correctly. Try resetting it and check the loaded code."),
             Self::Disconnected => write!(f, "device disconnected unexpectedly"),
             Self::UnsupportedVersion(v) => write!(f, "device implements an unsupported protocol version: {}", v),
-            Self::InvalidSpeed => write!(f, "the device could not set the speed"),
+            Self::Busy => write!(f, "the serial port is already locked by another process"),
             Self::Open(e) => write!(f, "could not open serial port file: {}", e),
             Self::Other(e) => write!(f, "error during transmission: {}", e),
         }
     }
 }
 
-pub fn communicate<P: AsRef<Path>>(
-    serial: P,
-) -> Result<(mpsc::Sender<i16>, JoinHandle<Result<(), Error>>), Error> {
-    let settings = SerialPortSettings {
-        baud_rate: 115200,
-        timeout: Duration::from_millis(1000),
-        ..SerialPortSettings::default()
-    };
-    let mut serial = serialport::open_with_settings(serial.as_ref(), &settings)?;
-    thread::sleep(Duration::from_millis(2000));
-    serial.write_all(b"smov")?;
+/// Serialize `msg` with postcard, COBS-encode it and write the delimited frame.
+fn write_frame<T: Serialize, W: io::Write + ?Sized>(port: &mut W, msg: &T) -> Result<(), Error> {
+    let payload = postcard::to_stdvec(msg)
+        .map_err(|e| Error::Other(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+    let mut encoded = vec![0u8; cobs::max_encoding_length(payload.len())];
+    let len = cobs::encode(&payload, &mut encoded);
+    encoded.truncate(len);
+    encoded.push(FRAME_DELIMITER);
+    port.write_all(&encoded)?;
+    Ok(())
+}
+
+/// Pull bytes from `port` into `buf` until a full frame (up to and including the next
+/// delimiter) is available, then COBS-decode and deserialize it. Returns `Ok(None)` once
+/// `stop` is set, which it is only checked between reads so a quiet-but-connected device
+/// never delays shutdown by more than one read timeout.
+///
+/// Buffering the bytes like this, instead of `read_exact`-ing a fixed number of bytes,
+/// means a reader that starts mid-frame (or after a desync) can always recover on the
+/// next `0x00`.
+fn read_frame<T, R>(port: &mut R, buf: &mut Vec<u8>, stop: &AtomicBool) -> Result<Option<T>, Error>
+where
+    T: for<'de> Deserialize<'de>,
+    R: Read + ?Sized,
+{
+    loop {
+        while let Some(pos) = buf.iter().position(|&b| b == FRAME_DELIMITER) {
+            let frame: Vec<u8> = buf.drain(..=pos).collect();
+            let frame = &frame[..frame.len() - 1];
+            let mut decoded = vec![0u8; frame.len()];
+            let message = cobs::decode(frame, &mut decoded)
+                .ok()
+                .and_then(|len| postcard::from_bytes(&decoded[..len]).ok());
+            match message {
+                Some(message) => return Ok(Some(message)),
+                // A desynced, garbage or empty frame must not kill the reader: skip it
+                // and keep scanning for the next delimiter, same as COBS's own resync
+                // guarantee.
+                None => continue,
+            }
+        }
+        if stop.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        let mut chunk = [0u8; 64];
+        let n = match port.read(&mut chunk) {
+            Ok(n) => n,
+            // The port is opened with a read timeout so the reader can stay responsive;
+            // a quiet device is not a dead one, so keep waiting instead of tearing the
+            // thread down.
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::TimedOut
+                        | io::ErrorKind::WouldBlock
+                        | io::ErrorKind::Interrupted
+                ) =>
+            {
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if n == 0 {
+            return Err(Error::Disconnected);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+/// Take an advisory, exclusive lock on the already-open `fd` so a second process opening
+/// the same device fails with [`Error::Busy`] instead of fighting over the handshake.
+#[cfg(unix)]
+fn lock_fd(fd: std::os::unix::io::RawFd) -> Result<(), Error> {
+    let ret = unsafe { libc::flock(fd, libc::LOCK_EX | libc::LOCK_NB) };
+    if ret != 0 {
+        let err = io::Error::last_os_error();
+        return Err(match err.kind() {
+            io::ErrorKind::WouldBlock => Error::Busy,
+            _ => Error::Other(err),
+        });
+    }
+    Ok(())
+}
+
+/// A transport that can hand out an independent reader and writer, mirroring two
+/// separate fds onto the same underlying device. Unlike sharing one handle behind a
+/// mutex, this means a blocking read can never stall an outgoing keepalive or speed
+/// command (or vice versa).
+trait Transport: Read + Write + Send + Sized + 'static {
+    type Reader: Read + Send + 'static;
+    type Writer: Write + Send + 'static;
+
+    fn split(self) -> Result<(Self::Reader, Self::Writer), Error>;
+}
+
+impl Transport for Box<dyn serialport::SerialPort> {
+    type Reader = Box<dyn serialport::SerialPort>;
+    type Writer = Box<dyn serialport::SerialPort>;
+
+    fn split(self) -> Result<(Self::Reader, Self::Writer), Error> {
+        let writer = self.try_clone()?;
+        Ok((self, writer))
+    }
+}
+
+#[cfg(unix)]
+impl Transport for std::os::unix::net::UnixStream {
+    type Reader = std::os::unix::net::UnixStream;
+    type Writer = std::os::unix::net::UnixStream;
+
+    fn split(self) -> Result<(Self::Reader, Self::Writer), Error> {
+        let writer = self.try_clone()?;
+        Ok((self, writer))
+    }
+}
+
+type CommunicateResult = Result<
+    (
+        mpsc::Sender<i16>,
+        JoinHandle<Result<(), Error>>,
+        mpsc::Receiver<Status>,
+    ),
+    Error,
+>;
+
+/// Perform the `"smov"` + version handshake over `io`.
+fn handshake<T: Read + Write>(io: &mut T) -> Result<(), Error> {
+    io.write_all(b"smov")?;
     let mut buf = [0; 4];
-    serial.read_exact(&mut buf)?;
+    io.read_exact(&mut buf)?;
     if &buf != b"smov" {
         return Err(Error::WrongDevice);
     }
     let mut version = [0; 2];
-    serial.read_exact(&mut version)?;
+    io.read_exact(&mut version)?;
     let version = u16::from_be_bytes(version);
-    if version == 0 {
-        serial.write_all(&[0x00])?;
-    } else {
-        serial.write_all(&[0x01])?;
+    if version != 0 {
+        io.write_all(&[0x01])?;
         return Err(Error::UnsupportedVersion(version));
     }
-    let (tx, rx) = mpsc::channel::<i16>();
-    let handle = thread::spawn(move || loop {
-        match rx.recv_timeout(Duration::from_millis(100)) {
-            Ok(speed) => {
-                let speed = speed.to_be_bytes();
-                serial.write_all(&[0x01, speed[0], speed[1]])?;
-                let mut result = [0; 1];
-                serial.read_exact(&mut result)?;
-                if result[0] != 0 {
-                    return Err(Error::InvalidSpeed);
+    io.write_all(&[0x00])?;
+    Ok(())
+}
+
+/// Spawn the reader thread that forwards decoded [`Status`] frames to `status_tx` until
+/// the link errors out, the status channel is dropped, or `stop` is set. Returns the
+/// link's terminal error, if any, once joined — a connected-but-silent device never
+/// hits this on its own, so callers must set `stop` (and join) to end the session.
+///
+/// The returned handle's `join` also drops `io`, so joining it is the only reliable way
+/// to know the reader's handle onto the transport has closed.
+fn spawn_reader<T: Read + Send + 'static>(
+    mut io: T,
+    status_tx: mpsc::Sender<Status>,
+    stop: Arc<AtomicBool>,
+) -> JoinHandle<Option<Error>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        loop {
+            match read_frame(&mut io, &mut buf, &stop) {
+                Ok(Some(status)) => {
+                    if status_tx.send(status).is_err() {
+                        return None;
+                    }
                 }
+                Ok(None) => return None,
+                Err(e) => return Some(e),
             }
-            Err(RecvTimeoutError::Timeout) => {
-                serial.write_all(&[0x00])?;
-                let mut result = [0; 1];
-                serial.read_exact(&mut result)?;
-                if result[0] != 0 {
-                    return Err(Error::Disconnected);
+        }
+    })
+}
+
+/// Combine the writer's result with the reader's terminal error (if any), preferring
+/// whichever actually failed, so a caller never sees `Ok` on a link the reader already
+/// observed go down.
+fn merge_results(writer: Result<(), Error>, reader: Option<Error>) -> Result<(), Error> {
+    writer.and(reader.map_or(Ok(()), Err))
+}
+
+/// Pull speed commands off `rx` and write them (or keepalives, on idle) to `io` until the
+/// link errors out or the caller drops its `Sender`. `rx` is handed back so a supervisor
+/// can resume pulling from the very same channel after reconnecting.
+fn drive_writer<T: Write>(
+    mut io: T,
+    rx: mpsc::Receiver<i16>,
+) -> (Result<(), Error>, mpsc::Receiver<i16>) {
+    loop {
+        let result = match rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(speed) => write_frame(&mut io, &Command::Speed(speed)),
+            Err(RecvTimeoutError::Timeout) => write_frame(&mut io, &Command::Keepalive),
+            Err(_) => break (Ok(()), rx),
+        };
+        if let Err(e) = result {
+            break (Err(e), rx);
+        }
+    }
+}
+
+/// Perform the handshake over `io`, then spawn the reader and writer threads that drive
+/// the rest of the session.
+///
+/// This is transport-agnostic: `io` can be a real serial port, a Unix socket, or in
+/// tests a loopback pair that replays scripted handshake and status bytes.
+fn communicate_over<T: Transport>(mut io: T) -> CommunicateResult {
+    handshake(&mut io)?;
+
+    // The read and write halves run on independent threads over independent handles
+    // (not a shared lock), mirroring the device's own ability to report telemetry at
+    // any time rather than only as a reply to a command.
+    let (reader, writer) = io.split()?;
+
+    let (tx, rx) = mpsc::channel::<i16>();
+    let (status_tx, status_rx) = mpsc::channel::<Status>();
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let reader_handle = spawn_reader(reader, status_tx, stop.clone());
+    let handle = thread::spawn(move || {
+        let (result, _rx) = drive_writer(writer, rx);
+        // Signal the reader and wait for its handle onto the transport to close before
+        // reporting how the session ended: otherwise the reader thread (which never
+        // exits on its own against a connected-but-silent device) leaks, keeping its
+        // dup'd fd — and the advisory flock with it — open indefinitely.
+        stop.store(true, Ordering::Relaxed);
+        let reader_result = reader_handle.join().unwrap_or(None);
+        merge_results(result, reader_result)
+    });
+
+    Ok((tx, handle, status_rx))
+}
+
+/// Open and lock the serial port at `serial`, waiting out the bootloader reset delay.
+///
+/// On Unix, `SerialPort` itself has no way to get at the raw fd (only the concrete
+/// `TTYPort` does), so the port is opened as a `TTYPort`, locked, and only then erased to
+/// the `Box<dyn SerialPort>` the rest of this module works with.
+#[cfg(unix)]
+fn open_serial<P: AsRef<Path>>(
+    serial: P,
+    config: &Config,
+) -> Result<Box<dyn serialport::SerialPort>, Error> {
+    use std::os::unix::io::AsRawFd;
+
+    let settings = SerialPortSettings {
+        baud_rate: config.baud_rate,
+        timeout: config.timeout,
+        parity: config.parity,
+        stop_bits: config.stop_bits,
+        ..SerialPortSettings::default()
+    };
+    let serial = serialport::TTYPort::open(serial.as_ref(), &settings)?;
+    lock_fd(serial.as_raw_fd())?;
+    thread::sleep(Duration::from_millis(2000));
+    Ok(Box::new(serial))
+}
+
+#[cfg(not(unix))]
+fn open_serial<P: AsRef<Path>>(
+    serial: P,
+    config: &Config,
+) -> Result<Box<dyn serialport::SerialPort>, Error> {
+    let settings = SerialPortSettings {
+        baud_rate: config.baud_rate,
+        timeout: config.timeout,
+        parity: config.parity,
+        stop_bits: config.stop_bits,
+        ..SerialPortSettings::default()
+    };
+    let serial = serialport::open_with_settings(serial.as_ref(), &settings)?;
+    thread::sleep(Duration::from_millis(2000));
+    Ok(serial)
+}
+
+/// Open the serial port at `serial` and start communication with the micro-controller.
+pub fn communicate<P: AsRef<Path>>(serial: P, config: Config) -> CommunicateResult {
+    communicate_over(open_serial(serial, &config)?)
+}
+
+/// Connect to the micro-controller over a Unix socket instead of a serial port, e.g. to
+/// develop against a simulated device on a machine with no hardware attached.
+///
+/// `timeout` bounds the reader's blocking `read`, the same way the serial port's own
+/// timeout does, so the reader stays responsive to `stop` instead of being able to block
+/// shutdown forever against a quiet peer.
+#[cfg(unix)]
+pub fn communicate_unix<P: AsRef<Path>>(path: P, timeout: Duration) -> CommunicateResult {
+    let stream = std::os::unix::net::UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(timeout))?;
+    communicate_over(stream)
+}
+
+/// Backoff schedule used by [`communicate_supervised`] between reconnect attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Give up and emit [`ConnectionEvent::GaveUp`] after this many failed attempts in a
+    /// row. `None` means retry forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+/// Link state transitions emitted by [`communicate_supervised`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The link dropped and a reconnect attempt is about to start.
+    Reconnecting,
+    /// The handshake succeeded, on the first connection or again after a reconnect.
+    Reconnected,
+    /// `max_attempts` was reached; no further reconnects will be attempted.
+    GaveUp,
+}
+
+/// A link error worth retrying: the device dropped off the bus or a read/write failed,
+/// either of which can clear up on its own. Protocol-level errors such as
+/// [`Error::WrongDevice`], [`Error::UnsupportedVersion`] or [`Error::Busy`] are permanent:
+/// retrying the same handshake against the same device can never succeed, so the
+/// supervisor gives up immediately instead of retrying forever with no feedback.
+fn is_transient(e: &Error) -> bool {
+    matches!(e, Error::Disconnected | Error::Other(_))
+}
+
+/// Sleep out the next backoff delay, reporting progress on `events`.
+///
+/// Returns `false` (having emitted [`ConnectionEvent::GaveUp`]) once `max_attempts` is
+/// exhausted, in which case the caller should stop retrying.
+fn back_off(
+    policy: &RetryPolicy,
+    attempt: &mut u32,
+    events: &mpsc::Sender<ConnectionEvent>,
+) -> bool {
+    if let Some(max_attempts) = policy.max_attempts {
+        if *attempt >= max_attempts {
+            let _ = events.send(ConnectionEvent::GaveUp);
+            return false;
+        }
+    }
+    let delay = policy
+        .base_delay
+        .saturating_mul(1 << (*attempt).min(16))
+        .min(policy.max_delay);
+    thread::sleep(delay);
+    *attempt += 1;
+    true
+}
+
+/// Like [`communicate`], but re-establishes the link (closing the port, backing off per
+/// `policy`, reopening and re-running the handshake) whenever the device disconnects,
+/// instead of letting the whole session die.
+///
+/// The returned `Sender<i16>` survives across reconnects: speed commands sent to it are
+/// queued and delivered to whichever connection attempt is current. `policy` is shared so
+/// a caller can adjust the backoff schedule of an already-running supervisor.
+pub fn communicate_supervised<P>(
+    serial: P,
+    config: Config,
+    policy: Arc<Mutex<RetryPolicy>>,
+) -> (
+    mpsc::Sender<i16>,
+    mpsc::Receiver<Status>,
+    mpsc::Receiver<ConnectionEvent>,
+)
+where
+    P: AsRef<Path> + Send + 'static,
+{
+    let (tx, mut rx) = mpsc::channel::<i16>();
+    let (status_tx, status_rx) = mpsc::channel::<Status>();
+    let (event_tx, event_rx) = mpsc::channel::<ConnectionEvent>();
+
+    thread::spawn(move || {
+        let mut attempt: u32 = 0;
+        loop {
+            let port = match open_serial(&serial, &config).and_then(|mut port| {
+                handshake(&mut port)?;
+                Ok(port)
+            }) {
+                Ok(port) => {
+                    attempt = 0;
+                    if event_tx.send(ConnectionEvent::Reconnected).is_err() {
+                        return;
+                    }
+                    port
+                }
+                Err(e) if is_transient(&e) => {
+                    if event_tx.send(ConnectionEvent::Reconnecting).is_err() {
+                        return;
+                    }
+                    let current_policy = *policy.lock().unwrap();
+                    if !back_off(&current_policy, &mut attempt, &event_tx) {
+                        return;
+                    }
+                    continue;
                 }
+                Err(_) => return, // not a transient link error: no point retrying
+            };
+
+            let (reader, writer) = match port.split() {
+                Ok(halves) => halves,
+                Err(e) if is_transient(&e) => {
+                    if event_tx.send(ConnectionEvent::Reconnecting).is_err() {
+                        return;
+                    }
+                    let current_policy = *policy.lock().unwrap();
+                    if !back_off(&current_policy, &mut attempt, &event_tx) {
+                        return;
+                    }
+                    continue;
+                }
+                Err(_) => return, // not a transient link error: no point retrying
+            };
+            let stop = Arc::new(AtomicBool::new(false));
+            let reader_handle = spawn_reader(reader, status_tx.clone(), stop.clone());
+
+            let (result, returned_rx) = drive_writer(writer, rx);
+            rx = returned_rx;
+
+            // Signal the reader and wait for its handle onto the transport to close
+            // before deciding what to do next: the advisory flock is only released once
+            // every fd/handle onto the port has closed, and joining also surfaces a
+            // disconnect the reader alone may have noticed.
+            stop.store(true, Ordering::Relaxed);
+            let reader_result = reader_handle.join().unwrap_or(None);
+
+            match merge_results(result, reader_result) {
+                Ok(()) => return, // the caller dropped its Sender: a clean shutdown
+                Err(e) if is_transient(&e) => {
+                    if event_tx.send(ConnectionEvent::Reconnecting).is_err() {
+                        return;
+                    }
+                    let current_policy = *policy.lock().unwrap();
+                    if !back_off(&current_policy, &mut attempt, &event_tx) {
+                        return;
+                    }
+                }
+                Err(_) => return, // not a transient link error: no point retrying
             }
-            Err(_) => return Ok(()),
         }
     });
-    Ok((tx, handle))
+
+    (tx, status_rx, event_rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Replays pre-recorded bytes, then reports a read timeout instead of EOF once they're
+    /// exhausted, the same way a real serial/socket transport does against a
+    /// quiet-but-connected device. Without this, the reader would see `Ok(0)` and exit
+    /// with a spurious [`Error::Disconnected`] as soon as the script runs out, racing the
+    /// test's own shutdown via `stop`.
+    struct BlockingCursor(Cursor<Vec<u8>>);
+
+    impl Read for BlockingCursor {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::new(io::ErrorKind::WouldBlock, "no more scripted bytes")),
+                n => Ok(n),
+            }
+        }
+    }
+
+    /// A scripted loopback transport: reads replay pre-recorded bytes "from the device",
+    /// writes are captured separately so they can be asserted on. This lets the handshake
+    /// and speed-command loop be exercised without a physical Arduino.
+    struct Loopback {
+        input: BlockingCursor,
+        output: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl Read for Loopback {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for Loopback {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct LoopbackWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for LoopbackWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Transport for Loopback {
+        type Reader = BlockingCursor;
+        type Writer = LoopbackWriter;
+
+        fn split(self) -> Result<(Self::Reader, Self::Writer), Error> {
+            Ok((self.input, LoopbackWriter(self.output)))
+        }
+    }
+
+    fn encode_frame<T: Serialize>(msg: &T) -> Vec<u8> {
+        let payload = postcard::to_stdvec(msg).unwrap();
+        let mut encoded = vec![0u8; cobs::max_encoding_length(payload.len())];
+        let len = cobs::encode(&payload, &mut encoded);
+        encoded.truncate(len);
+        encoded.push(FRAME_DELIMITER);
+        encoded
+    }
+
+    #[test]
+    fn communicate_over_handshakes_and_decodes_telemetry() {
+        let status = Status {
+            pressure: 12.5,
+            flow: 3.0,
+            rpm: 900,
+            error_flags: 0,
+        };
+
+        let mut script = Vec::new();
+        script.extend_from_slice(b"smov"); // device echoes the magic back
+        script.extend_from_slice(&0u16.to_be_bytes()); // version 0
+        script.extend_from_slice(&encode_frame(&status));
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let io = Loopback {
+            input: BlockingCursor(Cursor::new(script)),
+            output: output.clone(),
+        };
+
+        let (tx, handle, status_rx) = communicate_over(io).expect("handshake should succeed");
+
+        let received = status_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("telemetry frame should be decoded");
+        assert_eq!(received.pressure, status.pressure);
+        assert_eq!(received.rpm, status.rpm);
+
+        drop(tx);
+        handle
+            .join()
+            .unwrap()
+            .expect("writer should shut down cleanly");
+
+        let written = output.lock().unwrap().clone();
+        assert_eq!(&written[..4], b"smov");
+        assert_eq!(written[4], 0x00); // handshake ack for the supported version
+    }
+
+    #[test]
+    fn is_transient_retries_link_errors_but_not_protocol_errors() {
+        assert!(is_transient(&Error::Disconnected));
+        assert!(is_transient(&Error::Other(io::Error::new(
+            io::ErrorKind::Other,
+            "boom"
+        ))));
+
+        assert!(!is_transient(&Error::WrongDevice));
+        assert!(!is_transient(&Error::UnsupportedVersion(1)));
+        assert!(!is_transient(&Error::Busy));
+    }
+
+    #[test]
+    fn back_off_doubles_the_delay_up_to_the_cap() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            max_attempts: None,
+        };
+        let (events, _rx) = mpsc::channel();
+        let mut attempt = 0;
+
+        assert!(back_off(&policy, &mut attempt, &events)); // 1ms, attempt -> 1
+        assert_eq!(attempt, 1);
+        assert!(back_off(&policy, &mut attempt, &events)); // 2ms, attempt -> 2
+        assert_eq!(attempt, 2);
+        assert!(back_off(&policy, &mut attempt, &events)); // 4ms (capped), attempt -> 3
+        assert_eq!(attempt, 3);
+        assert!(back_off(&policy, &mut attempt, &events)); // still capped at 4ms
+        assert_eq!(attempt, 4);
+    }
+
+    #[test]
+    fn back_off_gives_up_after_max_attempts_and_emits_the_event() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            max_attempts: Some(2),
+        };
+        let (events, rx) = mpsc::channel();
+        let mut attempt = 0;
+
+        assert!(back_off(&policy, &mut attempt, &events));
+        assert_eq!(attempt, 1);
+        assert!(back_off(&policy, &mut attempt, &events));
+        assert_eq!(attempt, 2);
+        assert!(!back_off(&policy, &mut attempt, &events));
+        assert_eq!(attempt, 2); // give-up does not consume another attempt
+
+        assert_eq!(rx.try_recv().unwrap(), ConnectionEvent::GaveUp);
+    }
 }
@@ -1,6 +1,12 @@
-use smov::{communicate, serialport::SerialPortType};
+use smov::{
+    communicate, communicate_supervised, communicate_unix, serialport::SerialPortType,
+    ConnectionEvent, Config, RetryPolicy,
+};
 use std::io::BufRead;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -15,37 +21,145 @@ enum Command {
         /// The serial device to which is connected the arduino
         #[structopt(parse(from_os_str))]
         serial: Option<PathBuf>,
+
+        /// Connect to a Unix socket instead of a serial device, e.g. to develop against
+        /// a simulated controller without hardware attached
+        #[structopt(long, parse(from_os_str), conflicts_with = "serial")]
+        socket: Option<PathBuf>,
+
+        /// The baud rate to open the serial port with
+        #[structopt(long, default_value = "115200")]
+        baud: u32,
+
+        /// The read/write timeout, in milliseconds
+        #[structopt(long = "timeout-ms", default_value = "1000")]
+        timeout_ms: u64,
+
+        /// Automatically re-establish the link (with exponential backoff) if the device
+        /// disconnects, instead of exiting. Only applies to a serial device, not to --socket.
+        #[structopt(long)]
+        reconnect: bool,
+
+        /// Base delay of the reconnect backoff, in milliseconds
+        #[structopt(long = "reconnect-base-delay-ms", default_value = "500")]
+        reconnect_base_delay_ms: u64,
+
+        /// Maximum delay of the reconnect backoff, in milliseconds
+        #[structopt(long = "reconnect-max-delay-ms", default_value = "30000")]
+        reconnect_max_delay_ms: u64,
+
+        /// Give up reconnecting after this many failed attempts in a row. 0 retries forever.
+        #[structopt(long = "reconnect-max-attempts", default_value = "0")]
+        reconnect_max_attempts: u32,
     },
 }
 
+/// Resolve the serial device to use: the one given on the command line, or the first
+/// USB serial port reported by the system.
+fn resolve_serial(serial: Option<PathBuf>) -> PathBuf {
+    if let Some(s) = serial {
+        return s;
+    }
+    match serialport::available_ports() {
+        Ok(v) => {
+            if let Some(s) = v
+                .iter()
+                .find(|s| matches!(s.port_type, SerialPortType::UsbPort(..)))
+            {
+                println!("Using port {}", s.port_name);
+                s.port_name.as_str().into()
+            } else {
+                eprintln!("No serial port available");
+                std::process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Could not list serial devices: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print telemetry as it arrives, interleaved with reading speed commands from stdin.
+fn print_status(status: impl IntoIterator<Item = smov::Status> + Send + 'static) {
+    thread::spawn(move || {
+        for status in status {
+            println!(
+                "pressure={} flow={} rpm={} error_flags={}",
+                status.pressure, status.flow, status.rpm, status.error_flags
+            );
+        }
+    });
+}
+
 fn main() {
     match Command::from_args() {
         Command::Detect { files } => unimplemented!(),
-        Command::Control { serial } => {
-            let file = if let Some(s) = serial {
-                s
-            } else {
-                match serialport::available_ports() {
-                    Ok(v) => {
-                        if let Some(s) = v
-                            .iter()
-                            .find(|s| matches!(s.port_type, SerialPortType::UsbPort(..)))
-                        {
-                            println!("Using port {}", s.port_name);
-                            s.port_name.as_str().into()
-                        } else {
-                            eprintln!("No serial port available");
-                            std::process::exit(1);
+        Command::Control {
+            serial,
+            socket,
+            baud,
+            timeout_ms,
+            reconnect,
+            reconnect_base_delay_ms,
+            reconnect_max_delay_ms,
+            reconnect_max_attempts,
+        } => {
+            let config = Config {
+                baud_rate: baud,
+                timeout: Duration::from_millis(timeout_ms),
+                ..Config::default()
+            };
+
+            if reconnect {
+                if socket.is_some() {
+                    eprintln!("--reconnect is not supported together with --socket");
+                    std::process::exit(1);
+                }
+                let policy = Arc::new(Mutex::new(RetryPolicy {
+                    base_delay: Duration::from_millis(reconnect_base_delay_ms),
+                    max_delay: Duration::from_millis(reconnect_max_delay_ms),
+                    max_attempts: if reconnect_max_attempts == 0 {
+                        None
+                    } else {
+                        Some(reconnect_max_attempts)
+                    },
+                }));
+                let (tx, status, events) =
+                    communicate_supervised(resolve_serial(serial), config, policy);
+                print_status(status);
+                thread::spawn(move || {
+                    for event in events {
+                        match event {
+                            ConnectionEvent::Reconnecting => {
+                                eprintln!("Link lost, reconnecting...")
+                            }
+                            ConnectionEvent::Reconnected => eprintln!("Link (re)established"),
+                            ConnectionEvent::GaveUp => {
+                                eprintln!("Giving up after too many failed reconnect attempts");
+                                std::process::exit(1);
+                            }
                         }
                     }
-                    Err(e) => {
-                        eprintln!("Could not list serial devices: {}", e);
-                        std::process::exit(1);
+                });
+                let stdin = std::io::stdin();
+                for line in stdin.lock().lines() {
+                    let speed = line.unwrap().trim().parse::<i16>().unwrap();
+                    if tx.send(speed).is_err() {
+                        break;
                     }
                 }
+                return;
+            }
+
+            let result = if let Some(socket) = socket {
+                communicate_unix(socket, config.timeout)
+            } else {
+                communicate(resolve_serial(serial), config)
             };
-            match communicate(file) {
-                Ok((tx, handle)) => {
+            match result {
+                Ok((tx, handle, status)) => {
+                    print_status(status);
                     let stdin = std::io::stdin();
                     for line in stdin.lock().lines() {
                         let speed = line.unwrap().trim().parse::<i16>().unwrap();